@@ -0,0 +1,79 @@
+//! Command queue capabilities.
+
+use queue::QueueType;
+
+/// General capability, supporting graphics, compute and transfer operations.
+#[derive(Debug)]
+pub enum General {}
+/// Graphics capability.
+#[derive(Debug)]
+pub enum Graphics {}
+/// Compute capability.
+#[derive(Debug)]
+pub enum Compute {}
+/// Transfer capability.
+#[derive(Debug)]
+pub enum Transfer {}
+
+/// Capability dispatcher, implemented by each of the marker types above.
+pub trait Capability {
+    /// Returns true if the given queue type supports this capability.
+    fn supported_by(queue_type: QueueType) -> bool;
+}
+
+impl Capability for General {
+    fn supported_by(queue_type: QueueType) -> bool {
+        match queue_type {
+            QueueType::General => true,
+            _ => false,
+        }
+    }
+}
+
+impl Capability for Graphics {
+    fn supported_by(queue_type: QueueType) -> bool {
+        match queue_type {
+            QueueType::General | QueueType::Graphics => true,
+            _ => false,
+        }
+    }
+}
+
+impl Capability for Compute {
+    fn supported_by(queue_type: QueueType) -> bool {
+        match queue_type {
+            QueueType::General | QueueType::Compute => true,
+            _ => false,
+        }
+    }
+}
+
+impl Capability for Transfer {
+    fn supported_by(_queue_type: QueueType) -> bool {
+        true
+    }
+}
+
+/// Marks that a queue of capability `Self` can also be used wherever a queue
+/// of capability `Target` is required.
+///
+/// A `Graphics` queue is also a `Transfer` queue, for instance, so code that
+/// only needs to issue transfer work can be handed a graphics-capable queue
+/// without unsafely re-tagging its type.
+pub trait Supports<Target> {}
+
+impl<C: Capability> Supports<C> for C {}
+
+impl Supports<Transfer> for General {}
+impl Supports<Graphics> for General {}
+impl Supports<Compute> for General {}
+
+impl Supports<Transfer> for Graphics {}
+impl Supports<Transfer> for Compute {}
+
+// `Graphics` and `Compute` deliberately don't implement `Supports` for each
+// other: they're modeled as disjoint dedicated capabilities, and a queue
+// type can support one without supporting the other (e.g.
+// `Compute::supported_by(QueueType::Graphics)` is `false`), so implying it
+// would let `into_weaker`/`take_supporting` mistype a queue group as a
+// capability its underlying queue doesn't actually have.
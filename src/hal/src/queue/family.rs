@@ -2,7 +2,7 @@
 
 use Backend;
 use queue::{CommandQueue, QueueType};
-use queue::capability::{Capability, Graphics, Compute};
+use queue::capability::{Capability, Supports, Graphics, Compute, Transfer};
 
 use std::collections::HashMap;
 use std::fmt::Debug;
@@ -30,6 +30,26 @@ pub trait QueueFamily: Debug {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct QueueFamilyId(pub usize);
 
+/// Identifier for a single queue within a queue family.
+///
+/// A cheap, copyable handle that names a queue without borrowing it, so it
+/// can be stashed in submission bookkeeping and resolved back to a
+/// `CommandQueue` via `QueueGroup::queue`/`queue_mut` later on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct QueueId(pub QueueFamilyId, pub usize);
+
+impl QueueId {
+    /// Returns the identifier of the queue family this queue belongs to.
+    pub fn family(&self) -> QueueFamilyId {
+        self.0
+    }
+
+    /// Returns the index of this queue within its family.
+    pub fn index(&self) -> usize {
+        self.1
+    }
+}
+
 // Only needed for backend implementations.
 #[doc(hidden)]
 pub struct RawQueueGroup<B: Backend> {
@@ -76,6 +96,70 @@ impl<B: Backend, C: Capability> QueueGroup<B, C> {
                 .collect(),
         }
     }
+
+    /// Returns the queue at position `id.index()` in this group's `queues`,
+    /// if there is one. Positions count from the start of this group: after
+    /// `split_off` or `take_n` they no longer match a queue's position in
+    /// its family.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id.family()` doesn't match this group's family.
+    pub fn queue(&self, id: QueueId) -> Option<&CommandQueue<B, C>> {
+        assert_eq!(self.family, id.family());
+        self.queues.get(id.index())
+    }
+
+    /// Returns the queue at position `id.index()` in this group's `queues`
+    /// mutably, if there is one. Positions are counted as in `queue`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id.family()` doesn't match this group's family.
+    pub fn queue_mut(&mut self, id: QueueId) -> Option<&mut CommandQueue<B, C>> {
+        assert_eq!(self.family, id.family());
+        self.queues.get_mut(id.index())
+    }
+
+    /// Splits the first `n` queues off into a new group with the same family
+    /// id, leaving this group with the remaining queues. Takes from the
+    /// front, matching `Queues::take_n`, so repeated calls hand out queues
+    /// in the same order as the family's queue list.
+    ///
+    /// This lets a subset of a family's queues be handed off to another
+    /// owner (e.g. a background streaming thread) while the rest stay with
+    /// the caller; no queue ends up owned by both groups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the number of queues in this group.
+    pub fn split_off(&mut self, n: usize) -> QueueGroup<B, C> {
+        assert!(n <= self.queues.len());
+        let rest = self.queues.split_off(n);
+        let taken = ::std::mem::replace(&mut self.queues, rest);
+        QueueGroup {
+            family: self.family,
+            queues: taken,
+        }
+    }
+
+    /// Narrows this queue group down to a weaker capability `D`.
+    ///
+    /// This lets a group acquired with a strong capability (e.g. `Graphics`)
+    /// be handed to a subsystem that only needs a weaker one (e.g.
+    /// `Transfer`), without unsafely re-tagging the underlying queues.
+    pub fn into_weaker<D>(self) -> QueueGroup<B, D>
+    where
+        C: Supports<D>,
+    {
+        QueueGroup {
+            family: self.family,
+            queues: self.queues
+                .into_iter()
+                .map(|CommandQueue(raw, _)| CommandQueue(raw, PhantomData))
+                .collect(),
+        }
+    }
 }
 
 /// Contains a list of all instantiated queue queues, grouped by their
@@ -103,4 +187,104 @@ impl<B: Backend> Queues<B> {
     pub fn take_raw(&mut self, id: QueueFamilyId) -> Option<Vec<B::CommandQueue>> {
         self.0.remove(&id).map(|group| group.queues)
     }
+
+    /// Removes the queue family with the passed id from the queue list and
+    /// returns it narrowed down to the weaker capability `D`.
+    ///
+    /// This is `take::<C>` followed by `QueueGroup::into_weaker`, for the
+    /// common case of acquiring a family by its real capability `C` and
+    /// immediately handing it off typed as a weaker `D`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the family doesn't expose required queue capabilities.
+    pub fn take_supporting<C, D>(&mut self, id: QueueFamilyId) -> Option<QueueGroup<B, D>>
+    where
+        C: Capability + Supports<D>,
+    {
+        self.take::<C>(id).map(QueueGroup::into_weaker)
+    }
+
+    /// Removes `count` queues from the front of the family's queue list and
+    /// returns them as a typed group, leaving the rest in place so a later
+    /// `take`/`take_n` call can pick up the remaining queues. Takes from the
+    /// same end as `QueueGroup::split_off`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the family doesn't expose required queue capabilities, or
+    /// if `count` is greater than the number of queues left in the family.
+    pub fn take_n<C: Capability>(&mut self, id: QueueFamilyId, count: usize) -> Option<QueueGroup<B, C>> {
+        let raw = self.0.get_mut(&id)?;
+        assert!(C::supported_by(raw.family.queue_type()));
+        assert!(count <= raw.queues.len());
+        let taken = raw.queues.drain(..count);
+        Some(QueueGroup {
+            family: id,
+            queues: taken.map(|q| CommandQueue(q, PhantomData)).collect(),
+        })
+    }
+}
+
+/// Searches `families` for one supporting capability `C` and matching the
+/// given predicate, returning its id.
+///
+/// Skips families that expose no queues at all (`max_queues() == 0`).
+pub fn find_family<Q, C, F>(families: &[Q], pred: F) -> Option<QueueFamilyId>
+where
+    Q: QueueFamily,
+    C: Capability,
+    F: Fn(&Q) -> bool,
+{
+    families
+        .iter()
+        .enumerate()
+        .find(|&(_, family)| {
+            family.max_queues() > 0 && C::supported_by(family.queue_type()) && pred(family)
+        })
+        .map(|(index, _)| QueueFamilyId(index))
+}
+
+/// Number of capabilities a queue type satisfies besides the bare minimum,
+/// used to prefer a dedicated family over a more general one.
+fn generality(queue_type: QueueType) -> usize {
+    match queue_type {
+        QueueType::Transfer => 0,
+        QueueType::Graphics | QueueType::Compute => 1,
+        QueueType::General => 2,
+    }
+}
+
+/// Picks the family best suited to dedicated `C` work, preferring a family
+/// whose queue type supports *only* `C` over a more general one (e.g. a
+/// dedicated transfer queue over a general graphics queue), since dedicated
+/// hardware queues are typically faster for this kind of work.
+fn pick_dedicated<Q, C>(families: &[Q]) -> Option<QueueFamilyId>
+where
+    Q: QueueFamily,
+    C: Capability,
+{
+    families
+        .iter()
+        .enumerate()
+        .filter(|&(_, family)| family.max_queues() > 0 && C::supported_by(family.queue_type()))
+        .min_by_key(|&(_, family)| generality(family.queue_type()))
+        .map(|(index, _)| QueueFamilyId(index))
+}
+
+/// Picks a family that can be used for graphics work.
+pub fn pick_graphics<Q: QueueFamily>(families: &[Q]) -> Option<QueueFamilyId> {
+    find_family::<_, Graphics, _>(families, |_| true)
+}
+
+/// Picks the family best suited for dedicated async transfer (uploads etc.),
+/// preferring a transfer-only family over a general-purpose one.
+pub fn pick_dedicated_transfer<Q: QueueFamily>(families: &[Q]) -> Option<QueueFamilyId> {
+    pick_dedicated::<_, Transfer>(families)
+}
+
+/// Picks the family best suited for async compute work, preferring a
+/// compute-only family over a general-purpose one.
+pub fn pick_async_compute<Q: QueueFamily>(families: &[Q]) -> Option<QueueFamilyId> {
+    pick_dedicated::<_, Compute>(families)
 }
@@ -0,0 +1,35 @@
+//! Command queues.
+//!
+//! Queues are the execution paths of the graphical and computation commands
+//! recorded in command buffer submissions. There are different types of
+//! queues, which can only execute commands of the capability they expose:
+//! graphics, compute, transfer, or general (all of the above).
+
+pub mod capability;
+pub mod family;
+pub mod schedule;
+
+use Backend;
+use std::marker::PhantomData;
+
+pub use self::capability::{Capability, Supports, General, Graphics, Compute, Transfer};
+pub use self::family::{QueueFamily, QueueFamilyId, QueueId, QueueGroup, Queues};
+
+/// The type of a queue, i.e. the union of capabilities its queue family exposes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum QueueType {
+    /// General queues support graphics, compute and transfer operations.
+    General,
+    /// Graphics queues support graphics and transfer operations.
+    Graphics,
+    /// Compute queues support compute and transfer operations.
+    Compute,
+    /// Transfer queues only support transfer operations.
+    Transfer,
+}
+
+/// A command queue capable of executing command buffers of capability `C`.
+///
+/// Submitting command buffers of the wrong capability is prevented at
+/// compile time, since `C` restricts which buffers can be passed in.
+pub struct CommandQueue<B: Backend, C>(pub(crate) B::CommandQueue, pub(crate) PhantomData<C>);
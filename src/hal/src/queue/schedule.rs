@@ -0,0 +1,385 @@
+//! Cross-family submission scheduling.
+//!
+//! Turns a batch of work items - each requiring some `Capability`, touching a
+//! set of resources, and possibly depending on other items - into a concrete
+//! per-queue submission order plus the synchronization needed to respect
+//! every dependency: a semaphore when a dependency crosses queues, a barrier
+//! when it doesn't. This lets callers describe *what* must happen before
+//! *what* and get automatic multi-queue scheduling instead of hand-wiring
+//! semaphores themselves.
+
+use queue::QueueType;
+use queue::capability::{Capability, General, Graphics, Compute, Transfer};
+use queue::family::QueueId;
+
+use std::collections::{HashMap, HashSet};
+
+/// Index of a work item within the batch passed to [`schedule`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubmissionId(pub usize);
+
+/// How a work item accesses one of its resources.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Access {
+    /// The item reads the resource without modifying it.
+    Read,
+    /// The item writes the resource.
+    Write,
+}
+
+/// A single unit of work to be scheduled onto a queue.
+#[derive(Debug, Clone)]
+pub struct WorkItem<R> {
+    /// The capability required to execute this item.
+    pub capability: QueueType,
+    /// Resources this item accesses, and how.
+    pub accesses: Vec<(R, Access)>,
+    /// Other items in the batch that must complete before this one starts,
+    /// in addition to any hazards implied by shared resource accesses.
+    pub depends_on: Vec<SubmissionId>,
+}
+
+/// A concrete queue the scheduler may assign work to, tagged with the
+/// capability its owning family actually exposes so dedicated queues can be
+/// preferred over general-purpose ones.
+#[derive(Debug, Copy, Clone)]
+pub struct AvailableQueue {
+    /// The queue's identifier.
+    pub id: QueueId,
+    /// The capability of the family this queue belongs to.
+    pub capability: QueueType,
+}
+
+/// A semaphore that `producer`'s queue signals on completion, and that
+/// `consumer`'s queue waits on before starting.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Semaphore {
+    /// The submission whose queue signals this semaphore.
+    pub producer: SubmissionId,
+    /// The submission whose queue waits on this semaphore.
+    pub consumer: SubmissionId,
+}
+
+/// A pipeline barrier between two hazarding submissions kept on the same queue.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Barrier {
+    /// The earlier submission.
+    pub producer: SubmissionId,
+    /// The later submission, which must wait for `producer` on the same queue.
+    pub consumer: SubmissionId,
+}
+
+/// The result of [`schedule`]: a submission order per queue, plus the
+/// synchronization needed to respect every dependency.
+#[derive(Debug)]
+pub struct Schedule {
+    /// Submissions assigned to each queue, in the order they must execute.
+    pub queues: HashMap<QueueId, Vec<SubmissionId>>,
+    /// The queue each submission was assigned to.
+    pub assignment: HashMap<SubmissionId, QueueId>,
+    /// Semaphores required for cross-queue dependencies.
+    pub semaphores: Vec<Semaphore>,
+    /// Barriers required for same-queue dependencies.
+    pub barriers: Vec<Barrier>,
+}
+
+/// Failure modes for [`schedule`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// The dependency graph contains a cycle, so no valid order exists.
+    Cycle,
+    /// No available queue supports the capability this submission requires.
+    Unsupported(SubmissionId),
+    /// A `depends_on` entry named a `SubmissionId` outside the batch.
+    InvalidDependency(SubmissionId),
+}
+
+/// Schedules `items` onto `available` queues.
+///
+/// Builds a DAG from each item's explicit `depends_on` plus the hazards
+/// implied by overlapping resource accesses (an edge runs from the earlier
+/// to the later item whenever they touch the same resource and at least one
+/// of them writes it), topologically orders it, then greedily assigns
+/// independent items to queues - routing work to a dedicated queue of its
+/// exact capability when one is available (staying on the one a chain is
+/// already running on, else load-balanced across several dedicated queues),
+/// and otherwise continuing a chain on the queue it is already running on.
+/// A dependency that crosses from one queue to
+/// another is covered by exactly one semaphore; one that stays on the same
+/// queue becomes a barrier instead.
+pub fn schedule<R: Eq + Clone>(
+    items: &[WorkItem<R>],
+    available: &[AvailableQueue],
+) -> Result<Schedule, ScheduleError> {
+    for item in items {
+        for &dep in &item.depends_on {
+            if dep.0 >= items.len() {
+                return Err(ScheduleError::InvalidDependency(dep));
+            }
+        }
+    }
+
+    let edges = dependency_edges(items);
+    let order = topological_order(items.len(), &edges)?;
+
+    let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &(from, to) in &edges {
+        predecessors.entry(to).or_insert_with(Vec::new).push(from);
+    }
+
+    let mut queues: HashMap<QueueId, Vec<SubmissionId>> = HashMap::new();
+    let mut assignment: HashMap<SubmissionId, QueueId> = HashMap::new();
+    let mut semaphores = Vec::new();
+    let mut barriers = Vec::new();
+
+    for submission in order {
+        let item = &items[submission.0];
+        let preds = predecessors
+            .get(&submission.0)
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+
+        let chosen = pick_queue(item.capability, preds, &assignment, &queues, available)
+            .ok_or(ScheduleError::Unsupported(submission))?;
+
+        for &pred_index in preds {
+            let pred = SubmissionId(pred_index);
+            let pred_queue = assignment[&pred];
+            if pred_queue == chosen {
+                barriers.push(Barrier { producer: pred, consumer: submission });
+            } else {
+                semaphores.push(Semaphore { producer: pred, consumer: submission });
+            }
+        }
+
+        assignment.insert(submission, chosen);
+        queues.entry(chosen).or_insert_with(Vec::new).push(submission);
+    }
+
+    Ok(Schedule { queues, assignment, semaphores, barriers })
+}
+
+/// Builds (from, to) edges: explicit dependencies plus resource hazards
+/// between items that touch the same resource with at least one write.
+fn dependency_edges<R: Eq + Clone>(items: &[WorkItem<R>]) -> Vec<(usize, usize)> {
+    let mut edges = HashSet::new();
+    for (to, item) in items.iter().enumerate() {
+        for dep in &item.depends_on {
+            edges.insert((dep.0, to));
+        }
+    }
+    for to in 0..items.len() {
+        for from in 0..to {
+            if hazard(&items[from], &items[to]) {
+                edges.insert((from, to));
+            }
+        }
+    }
+    edges.into_iter().collect()
+}
+
+/// Whether `a` and `b` touch a common resource with at least one write.
+fn hazard<R: Eq + Clone>(a: &WorkItem<R>, b: &WorkItem<R>) -> bool {
+    a.accesses.iter().any(|(ra, access_a)| {
+        b.accesses.iter().any(|(rb, access_b)| {
+            ra == rb && (*access_a == Access::Write || *access_b == Access::Write)
+        })
+    })
+}
+
+/// Kahn's algorithm; rejects the input if it isn't a DAG.
+fn topological_order(
+    count: usize,
+    edges: &[(usize, usize)],
+) -> Result<Vec<SubmissionId>, ScheduleError> {
+    let mut in_degree = vec![0usize; count];
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for &(from, to) in edges {
+        adjacency[from].push(to);
+        in_degree[to] += 1;
+    }
+
+    let mut ready: Vec<usize> = (0..count).filter(|&i| in_degree[i] == 0).collect();
+    ready.sort();
+    let mut order = Vec::with_capacity(count);
+
+    let mut cursor = 0;
+    while cursor < ready.len() {
+        let node = ready[cursor];
+        cursor += 1;
+        order.push(SubmissionId(node));
+
+        let mut newly_ready = Vec::new();
+        for &next in &adjacency[node] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                newly_ready.push(next);
+            }
+        }
+        newly_ready.sort();
+        ready.extend(newly_ready);
+    }
+
+    if order.len() != count {
+        return Err(ScheduleError::Cycle);
+    }
+    Ok(order)
+}
+
+/// Picks the queue for `capability`. A predecessor's dedicated queue wins
+/// first (staying on a dedicated queue a dependency chain is already on
+/// avoids both an unnecessary semaphore and the point of being dedicated);
+/// failing that, the least-loaded queue dedicated to exactly `capability` is
+/// preferred over a general one (e.g. routing transfer work to a dedicated
+/// transfer queue), load-balanced across ties so independent work spreads
+/// across every such queue instead of piling onto the first; failing that,
+/// any predecessor's queue (to keep a dependency chain on a single queue and
+/// avoid cross-queue sync); falling back to the least-loaded compatible
+/// queue.
+fn pick_queue(
+    capability: QueueType,
+    preds: &[usize],
+    assignment: &HashMap<SubmissionId, QueueId>,
+    queues: &HashMap<QueueId, Vec<SubmissionId>>,
+    available: &[AvailableQueue],
+) -> Option<QueueId> {
+    let compatible: Vec<&AvailableQueue> = available
+        .iter()
+        .filter(|q| supports(q.capability, capability))
+        .collect();
+    if compatible.is_empty() {
+        return None;
+    }
+
+    let used_by_preds: HashSet<QueueId> = preds
+        .iter()
+        .filter_map(|&p| assignment.get(&SubmissionId(p)).cloned())
+        .collect();
+
+    let dedicated: Vec<&&AvailableQueue> = compatible
+        .iter()
+        .filter(|q| q.capability == capability)
+        .collect();
+    if let Some(queue) = dedicated.iter().find(|q| used_by_preds.contains(&q.id)) {
+        return Some(queue.id);
+    }
+    if !dedicated.is_empty() {
+        return dedicated
+            .iter()
+            .min_by_key(|q| queues.get(&q.id).map(Vec::len).unwrap_or(0))
+            .map(|q| q.id);
+    }
+
+    if let Some(queue) = compatible.iter().find(|q| used_by_preds.contains(&q.id)) {
+        return Some(queue.id);
+    }
+
+    compatible
+        .iter()
+        .min_by_key(|q| queues.get(&q.id).map(Vec::len).unwrap_or(0))
+        .map(|q| q.id)
+}
+
+/// Whether a queue of type `queue_type` can execute work requiring `capability`,
+/// dispatched through the same `Capability` impls `QueueGroup`/`Queues` check
+/// elsewhere so the two stay in lockstep.
+fn supports(queue_type: QueueType, capability: QueueType) -> bool {
+    match capability {
+        QueueType::General => General::supported_by(queue_type),
+        QueueType::Graphics => Graphics::supported_by(queue_type),
+        QueueType::Compute => Compute::supported_by(queue_type),
+        QueueType::Transfer => Transfer::supported_by(queue_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use queue::family::QueueFamilyId;
+
+    fn queue(family: usize, index: usize, capability: QueueType) -> AvailableQueue {
+        AvailableQueue { id: QueueId(QueueFamilyId(family), index), capability }
+    }
+
+    fn item(capability: QueueType, depends_on: &[usize]) -> WorkItem<u32> {
+        WorkItem {
+            capability,
+            accesses: Vec::new(),
+            depends_on: depends_on.iter().map(|&i| SubmissionId(i)).collect(),
+        }
+    }
+
+    #[test]
+    fn rejects_cycles() {
+        let items = vec![
+            item(QueueType::Transfer, &[1]),
+            item(QueueType::Transfer, &[0]),
+        ];
+        let available = [queue(0, 0, QueueType::Transfer)];
+        match schedule(&items, &available) {
+            Err(ScheduleError::Cycle) => {}
+            other => panic!("expected Cycle, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_dependency() {
+        let items = vec![item(QueueType::Transfer, &[1])];
+        let available = [queue(0, 0, QueueType::Transfer)];
+        match schedule(&items, &available) {
+            Err(ScheduleError::InvalidDependency(SubmissionId(1))) => {}
+            other => panic!("expected InvalidDependency(1), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn same_queue_hazard_produces_barrier() {
+        let mut items = vec![
+            item(QueueType::Transfer, &[]),
+            item(QueueType::Transfer, &[]),
+        ];
+        items[0].accesses.push((1u32, Access::Write));
+        items[1].accesses.push((1u32, Access::Write));
+        let available = [queue(0, 0, QueueType::Transfer)];
+        let result = schedule(&items, &available).unwrap();
+
+        assert_eq!(result.barriers, vec![Barrier { producer: SubmissionId(0), consumer: SubmissionId(1) }]);
+        assert!(result.semaphores.is_empty());
+    }
+
+    #[test]
+    fn cross_queue_dependency_produces_semaphore() {
+        let items = vec![
+            item(QueueType::Transfer, &[]),
+            item(QueueType::General, &[0]),
+        ];
+        let available = [
+            queue(0, 0, QueueType::Transfer),
+            queue(1, 0, QueueType::General),
+        ];
+        let result = schedule(&items, &available).unwrap();
+
+        assert_eq!(result.semaphores, vec![Semaphore { producer: SubmissionId(0), consumer: SubmissionId(1) }]);
+        assert!(result.barriers.is_empty());
+    }
+
+    #[test]
+    fn sequential_chain_stays_on_one_queue() {
+        let items: Vec<_> = (0..6)
+            .map(|i| {
+                let deps = if i == 0 { Vec::new() } else { vec![i - 1] };
+                item(QueueType::Transfer, &deps)
+            })
+            .collect();
+        let available = [
+            queue(0, 0, QueueType::Transfer),
+            queue(1, 0, QueueType::Transfer),
+        ];
+        let result = schedule(&items, &available).unwrap();
+
+        assert!(result.semaphores.is_empty());
+        assert_eq!(result.barriers.len(), 5);
+        let queues_used: HashSet<QueueId> = result.assignment.values().cloned().collect();
+        assert_eq!(queues_used.len(), 1);
+    }
+}